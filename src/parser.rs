@@ -5,6 +5,8 @@ use crate::types::{make_extension_error, ErrorKind, RedisError, RedisResult, Val
 
 #[cfg(feature = "tokio-util")]
 use bytes::{Buf, BytesMut};
+#[cfg(all(feature = "tokio-util", feature = "bytes"))]
+use bytes::Bytes;
 #[cfg(feature = "tokio")]
 use tokio::io::AsyncRead;
 #[cfg(feature = "tokio-util")]
@@ -23,6 +25,103 @@ use combine::{
     Parser as _,
 };
 
+/// Classifies a RESP error line (`-ERR ...` or the RESP3 blob-error body)
+/// into a `RedisError` the same way regardless of which wire form produced it.
+fn classify_error(line: &str) -> RedisError {
+    let desc = "An error was signalled by the server";
+    let mut pieces = line.splitn(2, ' ');
+    let kind = match pieces.next().unwrap() {
+        "ERR" => ErrorKind::ResponseError,
+        "EXECABORT" => ErrorKind::ExecAbortError,
+        "LOADING" => ErrorKind::BusyLoadingError,
+        "NOSCRIPT" => ErrorKind::NoScriptError,
+        "MOVED" => ErrorKind::Moved,
+        "ASK" => ErrorKind::Ask,
+        "TRYAGAIN" => ErrorKind::TryAgain,
+        "CLUSTERDOWN" => ErrorKind::ClusterDown,
+        "CROSSSLOT" => ErrorKind::CrossSlot,
+        "MASTERDOWN" => ErrorKind::MasterDown,
+        code => return make_extension_error(code, pieces.next()),
+    };
+    match pieces.next() {
+        Some(detail) => RedisError::from((kind, desc, detail.to_string())),
+        None => RedisError::from((kind, desc)),
+    }
+}
+
+/// Pairs up the flattened elements of a RESP3 map reply (`%<n>\r\n` is
+/// parsed as `n * 2` consecutive values) into key/value tuples.
+fn pairs_from_flat(flat: Vec<Value>) -> Vec<(Value, Value)> {
+    let mut iter = flat.into_iter();
+    let mut pairs = Vec::with_capacity(iter.len() / 2);
+    while let (Some(key), Some(value)) = (iter.next(), iter.next()) {
+        pairs.push((key, value));
+    }
+    pairs
+}
+
+/// Configurable ceilings enforced while parsing a reply, so a hostile or
+/// buggy peer can't drive unbounded stack recursion or memory reservation
+/// before any bytes are actually consumed. Set via [`Parser::with_limits`].
+#[derive(Clone, Copy, Debug)]
+pub struct Limits {
+    /// Maximum nesting depth of arrays/maps/sets/pushes; a RESP3 attribute
+    /// prefix counts as one level for the value that follows it.
+    pub max_depth: usize,
+    /// Maximum declared element count of a single array/map/set/push.
+    pub max_elements: usize,
+    /// Maximum declared length of a single bulk string.
+    pub max_bulk_len: usize,
+}
+
+impl Limits {
+    /// No limits at all: the historical, trust-the-peer behavior.
+    pub const UNBOUNDED: Limits = Limits {
+        max_depth: usize::MAX,
+        max_elements: usize::MAX,
+        max_bulk_len: usize::MAX,
+    };
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Limits::UNBOUNDED
+    }
+}
+
+fn protocol_error(detail: &'static str) -> RedisError {
+    RedisError::from((ErrorKind::ResponseError, detail))
+}
+
+fn limit_error() -> RedisError {
+    protocol_error("reply exceeds configured limit")
+}
+
+/// Checks a declared element count or bulk-string length against `max`,
+/// failing fast before the caller attempts to reserve or recurse into it.
+fn check_count_limit(count: i64, max: usize) -> Result<usize, RedisError> {
+    if count < 0 {
+        return Ok(0);
+    }
+    if count as usize > max {
+        return Err(limit_error());
+    }
+    Ok(count as usize)
+}
+
+/// Checks `count` against `limits.max_elements` and `depth` against
+/// `limits.max_depth`, so a caller about to recurse into an aggregate's
+/// children can fail fast instead of reserving or descending into it.
+/// `limits`/`depth` are plain call-scoped values (see [`value`]), not global
+/// state, so concurrent parses on the same thread can never see each other's
+/// counters.
+fn check_aggregate(count: i64, depth: usize, limits: Limits) -> Result<usize, RedisError> {
+    if depth >= limits.max_depth {
+        return Err(limit_error());
+    }
+    check_count_limit(count, limits.max_elements)
+}
+
 struct ResultExtend<T, E>(Result<T, E>);
 
 impl<T, E> Default for ResultExtend<T, E>
@@ -58,7 +157,15 @@ where
     }
 }
 
+/// Parses a single RESP value. `limits` caps declared element counts and
+/// bulk lengths, and `depth` is how many aggregates (counting a RESP3
+/// attribute prefix as one) already enclose this call; both are plain parameters threaded down into every recursive call
+/// rather than thread-local state, so a parse in progress on one task can
+/// never observe or corrupt another task's counters when they happen to
+/// share an OS thread across `.await` points.
 fn value<'a, I>(
+    limits: Limits,
+    depth: usize,
 ) -> impl combine::Parser<I, Output = RedisResult<Value>, PartialState = AnySendPartialState>
 where
     I: RangeStream<Token = u8, Range = &'a [u8]>,
@@ -92,90 +199,514 @@ where
             })
         };
 
-        let data = || {
+        let double = || {
+            line().and_then(|line: &str| match line.trim().parse::<f64>() {
+                Err(_) => Err(StreamErrorFor::<I>::message_static_message(
+                    "Expected double, got garbage",
+                )),
+                Ok(value) => Ok(value),
+            })
+        };
+
+        let boolean = || choice((byte(b't').map(|_| true), byte(b'f').map(|_| false)));
+
+        // A streamed bulk string (`$?\r\n`) is a sequence of `;<len>\r\n<bytes>`
+        // chunks terminated by a zero-length `;0\r\n` chunk, used when the
+        // server doesn't know the total length up front.
+        let streamed_chunk = || {
+            byte(b';').with(int()).then_partial(move |&mut len| {
+                if len < 0 {
+                    combine::value(Err(protocol_error("invalid bulk length"))).left()
+                } else {
+                    match check_count_limit(len, limits.max_bulk_len) {
+                        Err(err) => combine::value(Err(err)).left().right(),
+                        Ok(len) => take(len)
+                            .skip(crlf())
+                            .map(|bs: &[u8]| Ok(bs.to_vec()))
+                            .right()
+                            .right(),
+                    }
+                }
+            })
+        };
+        let streamed_chunk_terminator = || byte(b';').with(byte(b'0')).skip(crlf());
+        // A streamed bulk string doesn't declare its chunk count up front, so
+        // instead of checking a count before the loop starts, cap the number
+        // of chunks `count_min_max` will even attempt to parse at
+        // `limits.max_elements`; a peer that keeps sending chunks past that
+        // without ever reaching the terminator fails to parse instead of
+        // growing `collected` without bound.
+        let streamed_data = || {
+            combine::parser::repeat::count_min_max(
+                0,
+                limits.max_elements,
+                combine::attempt(streamed_chunk()),
+            )
+            .skip(streamed_chunk_terminator())
+            .map(|chunks: Vec<RedisResult<Vec<u8>>>| {
+                let mut collected = Vec::new();
+                for chunk in chunks {
+                    match chunk {
+                        Ok(bytes) => collected.extend(bytes),
+                        Err(err) => return Err(err),
+                    }
+                }
+                Ok(Value::Data(collected.into()))
+            })
+        };
+
+        let fixed_data = || {
             int().then_partial(move |size| {
                 if *size < 0 {
-                    combine::value(Value::Nil).left()
+                    combine::value(Ok(Value::Nil)).left()
                 } else {
-                    take(*size as usize)
-                        .map(|bs: &[u8]| Value::Data(bs.to_vec()))
-                        .skip(crlf())
-                        .right()
+                    match check_count_limit(*size, limits.max_bulk_len) {
+                        Err(err) => combine::value(Err(err)).left().right(),
+                        Ok(size) => take(size)
+                            .map(|bs: &[u8]| Ok(Value::Data(bs.to_vec().into())))
+                            .skip(crlf())
+                            .right()
+                            .right(),
+                    }
                 }
             })
         };
 
-        let bulk = || {
-            int().then_partial(|&mut length| {
+        let data = || {
+            choice((
+                byte(b'?').with(crlf()).with(streamed_data()),
+                fixed_data(),
+            ))
+        };
+
+        // A streamed aggregate (`*?\r\n`, `%?\r\n`, `~?\r\n`) holds child
+        // values one at a time, terminated by `.\r\n` instead of a known
+        // count, used when the server doesn't know the total size up front.
+        let streamed_terminator = || byte(b'.').with(crlf());
+        // Streamed aggregates don't declare an element count up front either,
+        // so the same `count_min_max` capping used by `streamed_data` applies
+        // here: a peer streaming more than `limits.max_elements` children
+        // without ever reaching `.\r\n` fails to parse instead of growing
+        // `collected` without bound.
+        let streamed_values = || {
+            combine::parser::repeat::count_min_max(
+                0,
+                limits.max_elements,
+                // Check for the terminator before each child: otherwise the
+                // inline-command fallback in `value` would parse `.\r\n` as
+                // an element.
+                combine::attempt(
+                    combine::not_followed_by(
+                        combine::attempt(streamed_terminator()).map(|_| "`.` terminator"),
+                    )
+                    .with(value(limits, depth + 1)),
+                ),
+            )
+            .skip(streamed_terminator())
+            .map(|items: Vec<RedisResult<Value>>| {
+                let mut collected = Vec::with_capacity(items.len());
+                for item in items {
+                    match item {
+                        Ok(v) => collected.push(v),
+                        Err(err) => return Err(err),
+                    }
+                }
+                Ok(collected)
+            })
+        };
+        // The depth limit is still checked up front, before entering the
+        // streamed loop at all; the element count is capped lazily above.
+        let enter_streamed = || check_aggregate(0, depth, limits);
+
+        let fixed_bulk = || {
+            int().then_partial(move |&mut length| {
                 if length < 0 {
-                    combine::value(Value::Nil).map(Ok).left()
+                    combine::value(Ok(Value::Nil)).left()
+                } else {
+                    match check_aggregate(length, depth, limits) {
+                        Err(err) => combine::value(Err(err)).left().right(),
+                        Ok(length) => {
+                            combine::count_min_max(length, length, value(limits, depth + 1))
+                                .map(|result: ResultExtend<_, _>| result.0.map(Value::Bulk))
+                                .right()
+                                .right()
+                        }
+                    }
+                }
+            })
+        };
+
+        let bulk = || {
+            choice((
+                byte(b'?').with(crlf()).then_partial(move |_| match enter_streamed() {
+                    Err(err) => combine::value(Err(err)).left(),
+                    Ok(_) => streamed_values().map(|result| result.map(Value::Bulk)).right(),
+                }),
+                fixed_bulk(),
+            ))
+        };
+
+        let error = || line().map(classify_error);
+
+        let blob_error = || {
+            int().then_partial(move |&mut size| {
+                if size < 0 {
+                    combine::value(protocol_error("invalid bulk length")).left()
+                } else {
+                    match check_count_limit(size, limits.max_bulk_len) {
+                        Err(err) => combine::value(err).left().right(),
+                        Ok(size) => take(size)
+                            .skip(crlf())
+                            .and_then(|bs: &[u8]| {
+                                str::from_utf8(bs).map_err(StreamErrorFor::<I>::other)
+                            })
+                            .map(classify_error)
+                            .right()
+                            .right(),
+                    }
+                }
+            })
+        };
+
+        let big_number = || line().map(|line: &str| Value::BigNumber(line.to_string()));
+
+        let verbatim = || {
+            int().then_partial(move |&mut size| {
+                if size < 0 {
+                    combine::value(Err(protocol_error("invalid bulk length"))).left()
                 } else {
-                    let length = length as usize;
-                    combine::count_min_max(length, length, value())
-                        .map(|result: ResultExtend<_, _>| result.0.map(Value::Bulk))
+                    match check_count_limit(size, limits.max_bulk_len) {
+                        Err(err) => combine::value(Err(err)).left().right(),
+                        Ok(size) => take(size)
+                            .skip(crlf())
+                            .map(|bs: &[u8]| match str::from_utf8(bs) {
+                                Err(_) => Err(protocol_error("invalid utf-8 in verbatim string")),
+                                Ok(text) => Ok(match text.split_once(':') {
+                                    Some((format, text)) => Value::VerbatimString {
+                                        format: format.to_string(),
+                                        text: text.to_string(),
+                                    },
+                                    None => Value::VerbatimString {
+                                        format: String::new(),
+                                        text: text.to_string(),
+                                    },
+                                }),
+                            })
+                            .right()
+                            .right(),
+                    }
+                }
+            })
+        };
+
+        let fixed_set = || {
+            int().then_partial(move |&mut length| match check_aggregate(length, depth, limits) {
+                Err(err) => combine::value(Err(err)).left(),
+                Ok(length) => combine::count_min_max(length, length, value(limits, depth + 1))
+                    .map(|result: ResultExtend<_, _>| result.0.map(Value::Set))
+                    .right(),
+            })
+        };
+
+        let set = || {
+            choice((
+                byte(b'?').with(crlf()).then_partial(move |_| match enter_streamed() {
+                    Err(err) => combine::value(Err(err)).left(),
+                    Ok(_) => streamed_values().map(|result| result.map(Value::Set)).right(),
+                }),
+                fixed_set(),
+            ))
+        };
+
+        let fixed_map = || {
+            int().then_partial(move |&mut length| match check_aggregate(length, depth, limits) {
+                Err(err) => combine::value(Err(err)).left(),
+                Ok(length) => {
+                    combine::count_min_max(length * 2, length * 2, value(limits, depth + 1))
+                        .map(|result: ResultExtend<Vec<_>, _>| {
+                            result.0.map(pairs_from_flat).map(Value::Map)
+                        })
                         .right()
                 }
             })
         };
 
-        let error = || {
-            line().map(|line: &str| {
-                let desc = "An error was signalled by the server";
-                let mut pieces = line.splitn(2, ' ');
-                let kind = match pieces.next().unwrap() {
-                    "ERR" => ErrorKind::ResponseError,
-                    "EXECABORT" => ErrorKind::ExecAbortError,
-                    "LOADING" => ErrorKind::BusyLoadingError,
-                    "NOSCRIPT" => ErrorKind::NoScriptError,
-                    "MOVED" => ErrorKind::Moved,
-                    "ASK" => ErrorKind::Ask,
-                    "TRYAGAIN" => ErrorKind::TryAgain,
-                    "CLUSTERDOWN" => ErrorKind::ClusterDown,
-                    "CROSSSLOT" => ErrorKind::CrossSlot,
-                    "MASTERDOWN" => ErrorKind::MasterDown,
-                    code => return make_extension_error(code, pieces.next()),
-                };
-                match pieces.next() {
-                    Some(detail) => RedisError::from((kind, desc, detail.to_string())),
-                    None => RedisError::from((kind, desc)),
+        let map = || {
+            choice((
+                byte(b'?').with(crlf()).then_partial(move |_| match enter_streamed() {
+                    Err(err) => combine::value(Err(err)).left(),
+                    Ok(_) => streamed_values()
+                        .map(|result| result.map(pairs_from_flat).map(Value::Map))
+                        .right(),
+                }),
+                fixed_map(),
+            ))
+        };
+
+        let fixed_push = || {
+            int().then_partial(move |&mut length| match check_aggregate(length, depth, limits) {
+                Err(err) => combine::value(Err(err)).left(),
+                Ok(length) => combine::count_min_max(length, length, value(limits, depth + 1))
+                    .map(|result: ResultExtend<_, _>| result.0.map(Value::Push))
+                    .right(),
+            })
+        };
+
+        let push = || {
+            choice((
+                byte(b'?').with(crlf()).then_partial(move |_| match enter_streamed() {
+                    Err(err) => combine::value(Err(err)).left(),
+                    Ok(_) => streamed_values().map(|result| result.map(Value::Push)).right(),
+                }),
+                fixed_push(),
+            ))
+        };
+
+        // An inline command is exactly as untrusted as any other reply this
+        // file parses, so it gets the same limits: `max_bulk_len` bounds the
+        // length of the line itself and `max_elements` bounds how many
+        // whitespace-separated tokens can turn into `Value::Data` entries.
+        // The length is checked once `line()` has found the line's CRLF; the
+        // scan for that CRLF is not itself bounded.
+        let inline = || {
+            line().map(move |line: &str| {
+                if line.len() > limits.max_bulk_len {
+                    return Err(limit_error());
+                }
+                let mut args = Vec::new();
+                for arg in line.split_whitespace() {
+                    if args.len() >= limits.max_elements {
+                        return Err(limit_error());
+                    }
+                    args.push(Value::Data(arg.as_bytes().to_vec().into()));
                 }
+                Ok(Value::Bulk(args))
+            })
+        };
+
+        let attribute = || {
+            int().then_partial(move |&mut length| match check_aggregate(length, depth, limits) {
+                Err(err) => combine::value(Err(err)).left(),
+                Ok(length) => combine::count_min_max(length * 2, length * 2, value(limits, depth + 1))
+                    .then_partial(move |attrs: &mut ResultExtend<Vec<Value>, RedisError>| {
+                        // Take the attribute-map result so a protocol error
+                        // embedded in one of its values surfaces instead of
+                        // being silently dropped, the same as every other
+                        // aggregate parser in this file.
+                        match std::mem::replace(&mut attrs.0, Ok(Vec::new())) {
+                            Ok(_) => value(limits, depth + 1).left(),
+                            Err(err) => combine::value(Err(err)).right(),
+                        }
+                    })
+                    .right(),
             })
         };
 
         any_send_partial_state(choice((
-            byte(b'+').with(status().map(Ok)),
-            byte(b':').with(int().map(Value::Int).map(Ok)),
-            byte(b'$').with(data().map(Ok)),
-            byte(b'*').with(bulk()),
-            byte(b'-').with(error().map(Err)),
+            choice((
+                byte(b'+').with(status().map(Ok)),
+                byte(b':').with(int().map(Value::Int).map(Ok)),
+                byte(b'$').with(data()),
+                byte(b'*').with(bulk()),
+                byte(b'-').with(error().map(Err)),
+                byte(b'_').with(crlf()).map(|_| Ok(Value::Nil)),
+                byte(b'#').with(boolean()).skip(crlf()).map(Value::Boolean).map(Ok),
+                byte(b',').with(double()).map(Value::Double).map(Ok),
+                byte(b'(').with(big_number()).map(Ok),
+            )),
+            choice((
+                byte(b'!').with(blob_error().map(Err)),
+                byte(b'=').with(verbatim()),
+                byte(b'%').with(map()),
+                byte(b'~').with(set()),
+                byte(b'>').with(push()),
+                byte(b'|').with(attribute()),
+            )),
+            // Anything that isn't one of the known type leaders is an inline
+            // command (a CRLF-terminated, whitespace-separated line, as sent
+            // by telnet-style clients). Tried last so it only ever fires
+            // once every typed leader above has failed to match.
+            inline(),
         )))
     })
 }
 
+/// Where a complete top-level `$<len>\r\n<bytes>\r\n` bulk reply sits in a
+/// read buffer, as produced by [`bulk_bytes_span`].
+#[cfg(all(feature = "tokio-util", feature = "bytes"))]
+enum BulkSpan {
+    Nil(usize),
+    Data { start: usize, len: usize, total: usize },
+}
+
+/// Scans `buffer` for a complete top-level bulk-string reply without
+/// allocating, returning the byte range of its payload so the caller can
+/// carve a [`Bytes`] view out of the original `BytesMut` instead of copying
+/// it into a `Vec<u8>`. Returns `None` if `buffer` doesn't start with `$` or
+/// the reply hasn't fully arrived yet, in which case the caller should fall
+/// back to the regular combine-based decode.
+#[cfg(all(feature = "tokio-util", feature = "bytes"))]
+fn bulk_bytes_span(buffer: &[u8]) -> Option<BulkSpan> {
+    if buffer.first() != Some(&b'$') {
+        return None;
+    }
+    let line_end = buffer.windows(2).position(|w| w == b"\r\n")?;
+    let size: i64 = str::from_utf8(&buffer[1..line_end]).ok()?.trim().parse().ok()?;
+    if size < 0 {
+        return Some(BulkSpan::Nil(line_end + 2));
+    }
+    let size = size as usize;
+    let start = line_end + 2;
+    let total = start + size + 2;
+    if buffer.len() < total || &buffer[start + size..total] != b"\r\n" {
+        return None;
+    }
+    Some(BulkSpan::Data { start, len: size, total })
+}
+
 #[cfg(feature = "tokio-util")]
 #[derive(Default)]
 pub struct ValueCodec {
     state: AnySendPartialState,
+    limits: Limits,
+    /// Whether the last `decode` call left the combine-based slow path
+    /// mid-parse (an incomplete bulk string, waiting on more bytes). The
+    /// zero-copy fast path is only safe to take while this is `false`: taking
+    /// it anyway would resolve the value itself while `state` still points
+    /// at that abandoned continuation, corrupting the next unrelated
+    /// reply's slow-path resumption.
+    #[cfg(feature = "bytes")]
+    partial_in_progress: bool,
+}
+
+#[cfg(feature = "tokio-util")]
+impl ValueCodec {
+    /// Enforces `limits` while decoding, so a hostile or buggy peer can't
+    /// drive unbounded stack recursion or memory reservation with a crafted
+    /// reply. See [`Parser::with_limits`].
+    pub fn set_limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+}
+
+/// Writes `value` to `dst` as well-formed RESP, recursing into aggregates
+/// the same way [`value()`] parses them back out.
+#[cfg(feature = "tokio-util")]
+fn encode_value(value: &Value, dst: &mut BytesMut) {
+    fn write_header(leader: u8, len: usize, dst: &mut BytesMut) {
+        dst.extend_from_slice(&[leader]);
+        dst.extend_from_slice(len.to_string().as_bytes());
+        dst.extend_from_slice(b"\r\n");
+    }
+
+    match value {
+        Value::Nil => dst.extend_from_slice(b"$-1\r\n"),
+        Value::Okay => dst.extend_from_slice(b"+OK\r\n"),
+        Value::Status(status) => {
+            dst.extend_from_slice(b"+");
+            dst.extend_from_slice(status.as_bytes());
+            dst.extend_from_slice(b"\r\n");
+        }
+        Value::Int(i) => {
+            dst.extend_from_slice(b":");
+            dst.extend_from_slice(i.to_string().as_bytes());
+            dst.extend_from_slice(b"\r\n");
+        }
+        Value::Data(bytes) => {
+            write_header(b'$', bytes.as_ref().len(), dst);
+            dst.extend_from_slice(bytes.as_ref());
+            dst.extend_from_slice(b"\r\n");
+        }
+        Value::Bulk(items) => {
+            write_header(b'*', items.len(), dst);
+            for item in items {
+                encode_value(item, dst);
+            }
+        }
+        Value::Boolean(b) => dst.extend_from_slice(if *b { b"#t\r\n" } else { b"#f\r\n" }),
+        Value::Double(d) => {
+            dst.extend_from_slice(b",");
+            dst.extend_from_slice(d.to_string().as_bytes());
+            dst.extend_from_slice(b"\r\n");
+        }
+        Value::BigNumber(n) => {
+            dst.extend_from_slice(b"(");
+            dst.extend_from_slice(n.as_bytes());
+            dst.extend_from_slice(b"\r\n");
+        }
+        Value::VerbatimString { format, text } => {
+            write_header(b'=', format.len() + 1 + text.len(), dst);
+            dst.extend_from_slice(format.as_bytes());
+            dst.extend_from_slice(b":");
+            dst.extend_from_slice(text.as_bytes());
+            dst.extend_from_slice(b"\r\n");
+        }
+        Value::Map(pairs) => {
+            write_header(b'%', pairs.len(), dst);
+            for (k, v) in pairs {
+                encode_value(k, dst);
+                encode_value(v, dst);
+            }
+        }
+        Value::Set(items) => {
+            write_header(b'~', items.len(), dst);
+            for item in items {
+                encode_value(item, dst);
+            }
+        }
+        Value::Push(items) => {
+            write_header(b'>', items.len(), dst);
+            for item in items {
+                encode_value(item, dst);
+            }
+        }
+    }
 }
 
 #[cfg(feature = "tokio-util")]
 impl Encoder for ValueCodec {
-    type Item = Vec<u8>;
+    type Item = Value;
     type Error = RedisError;
     fn encode(&mut self, item: Self::Item, dst: &mut BytesMut) -> Result<(), Self::Error> {
-        dst.extend_from_slice(item.as_ref());
+        encode_value(&item, dst);
         Ok(())
     }
+}
 
 #[cfg(feature = "tokio-util")]
 impl Decoder for ValueCodec {
     type Item = Value;
     type Error = RedisError;
     fn decode(&mut self, bytes: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        // Opt-in zero-copy fast path: a top-level bulk string is handed back
+        // as a `Bytes` slice of the read buffer instead of being copied into
+        // a fresh `Vec<u8>`. Anything else (arrays, simple strings, RESP3
+        // aggregates, ...) still goes through the combine-based decode below,
+        // as does a bulk string while `partial_in_progress` is set, so this
+        // can't race the slow path's own in-flight continuation for the same
+        // bulk string split across an earlier `decode` call, and a declared
+        // length over `max_bulk_len` falls through to the slow path, which
+        // enforces it.
+        #[cfg(feature = "bytes")]
+        if !self.partial_in_progress {
+            match bulk_bytes_span(&bytes[..]) {
+                Some(BulkSpan::Nil(total)) => {
+                    bytes.advance(total);
+                    return Ok(Some(Value::Nil));
+                }
+                Some(BulkSpan::Data { start, len, total }) if len <= self.limits.max_bulk_len => {
+                    let chunk: Bytes = bytes.split_to(total).freeze();
+                    return Ok(Some(Value::Data(chunk.slice(start..start + len))));
+                }
+                Some(BulkSpan::Data { .. }) | None => {}
+            }
+        }
+
         let (opt, removed_len) = {
             let buffer = &bytes[..];
             let mut stream = combine::easy::Stream(combine::stream::PartialStream(buffer));
-            match combine::stream::decode(value(), &mut stream, &mut self.state) {
+            match combine::stream::decode(value(self.limits, 0), &mut stream, &mut self.state) {
                 Ok(x) => x,
                 Err(err) => {
                     let err = err
@@ -191,6 +722,10 @@ impl Decoder for ValueCodec {
             };
 
             bytes.advance(removed_len);
+            #[cfg(feature = "bytes")]
+            {
+                self.partial_in_progress = opt.is_none();
+            }
             match opt {
                 Some(result) => Ok(Some(result?)),
                 None => Ok(None),
@@ -198,16 +733,17 @@ impl Decoder for ValueCodec {
         }
     }
 }
-/// Parses a redis value asynchronously.
+/// Parses a redis value asynchronously, enforcing `limits`.
 #[cfg(feature = "tokio")]
 pub async fn parse_redis_value_async<R>(
     decoder: &mut combine::stream::Decoder<AnySendPartialState, PointerOffset<[u8]>>,
     read: &mut R,
+    limits: Limits,
 ) -> RedisResult<Value>
 where
     R: AsyncRead + std::marker::Unpin,
 {
-    let result = combine::decode_tokio_02!(*decoder, *read, value(), |input, _| {
+    let result = combine::decode_tokio_02!(*decoder, *read, value(limits, 0), |input, _| {
         combine::stream::easy::Stream::from(input)
     });
     match result {
@@ -225,9 +761,75 @@ where
     }
 }
 
+/// State for [`Parser::with_capacity`]'s bounded-read mode: a fixed-size
+/// read window plus the plain buffer and partial parse state it reads into,
+/// so memory stays flat instead of growing with however much a slow
+/// consumer lets accumulate.
+#[cfg(feature = "tokio")]
+struct BoundedParser {
+    buffer: Vec<u8>,
+    state: AnySendPartialState,
+    /// The fixed-size read window, allocated once and reused across every
+    /// `parse_value` call instead of being reallocated and zero-filled per
+    /// parsed reply.
+    window: Vec<u8>,
+}
+
+#[cfg(feature = "tokio")]
+impl BoundedParser {
+    async fn parse_value<R>(&mut self, read: &mut R, limits: Limits) -> RedisResult<Value>
+    where
+        R: AsyncRead + std::marker::Unpin,
+    {
+        use tokio::io::AsyncReadExt;
+
+        loop {
+            if !self.buffer.is_empty() {
+                let (opt, removed_len) = {
+                    let stream_buffer = &self.buffer[..];
+                    let mut stream =
+                        combine::easy::Stream(combine::stream::PartialStream(stream_buffer));
+                    match combine::stream::decode(value(limits, 0), &mut stream, &mut self.state) {
+                        Ok(x) => x,
+                        Err(err) => {
+                            let err = err
+                                .map_position(|pos| pos.translate_position(stream_buffer))
+                                .map_range(|range| format!("{:?}", range))
+                                .to_string();
+                            return Err(RedisError::from((
+                                ErrorKind::ResponseError,
+                                "parse error",
+                                err,
+                            )));
+                        }
+                    }
+                };
+                // Drop the consumed prefix, compacting whatever partial
+                // value is left to the front of the same allocation.
+                self.buffer.drain(..removed_len);
+                if let Some(result) = opt {
+                    return result;
+                }
+            }
+
+            let n = read.read(&mut self.window).await?;
+            if n == 0 {
+                return Err(RedisError::from((
+                    ErrorKind::ResponseError,
+                    "connection closed before a complete response was received",
+                )));
+            }
+            self.buffer.extend_from_slice(&self.window[..n]);
+        }
+    }
+}
+
 /// The internal redis response parser.
 pub struct Parser {
     decoder: combine::stream::decoder::Decoder<AnySendPartialState, PointerOffset<[u8]>>,
+    #[cfg(feature = "tokio")]
+    bounded: Option<BoundedParser>,
+    limits: Limits,
 }
 
 /// The parser can be used to parse redis responses into values.  Generally
@@ -242,15 +844,65 @@ impl Parser {
     pub fn new() -> Parser {
         Parser {
             decoder: combine::stream::decoder::Decoder::new(),
+            #[cfg(feature = "tokio")]
+            bounded: None,
+            limits: Limits::default(),
         }
     }
 
+    /// Creates a parser like [`Parser::new`], but enforces `limits` while
+    /// parsing, so a hostile or buggy peer can't drive unbounded stack
+    /// recursion or memory reservation with a crafted reply. Defaults are
+    /// permissive ([`Limits::UNBOUNDED`]); servers and proxies parsing
+    /// untrusted RESP should clamp this. Composes with [`Parser::with_capacity`]
+    /// via [`Parser::set_capacity`].
+    pub fn with_limits(limits: Limits) -> Parser {
+        Parser::new().set_limits(limits)
+    }
+
+    /// Sets the limits enforced while parsing, without disturbing any
+    /// bounded-read window already configured. See [`Parser::with_limits`].
+    pub fn set_limits(mut self, limits: Limits) -> Parser {
+        self.limits = limits;
+        self
+    }
+
+    /// Creates a parser like [`Parser::new`], but caps every read performed
+    /// by [`Parser::parse_value_async`] at `max_read` bytes (e.g. 8 KiB)
+    /// instead of letting the buffer grow to whatever a slow consumer lets
+    /// accumulate. Every complete value already in the buffer is parsed out
+    /// before the next read; a lingering partial value is compacted to the
+    /// front of the same allocation rather than reallocated. Composes with
+    /// [`Parser::with_limits`] via [`Parser::set_limits`] - a bounded read
+    /// window alone doesn't cap memory for one huge declared value, since the
+    /// accumulation buffer still grows across reads until a complete value
+    /// arrives, so servers and proxies parsing untrusted RESP should set
+    /// both.
+    #[cfg(feature = "tokio")]
+    pub fn with_capacity(max_read: usize) -> Parser {
+        Parser::new().set_capacity(max_read)
+    }
+
+    /// Sets the bounded-read window used by [`Parser::parse_value_async`],
+    /// without disturbing any limits already configured. See
+    /// [`Parser::with_capacity`].
+    #[cfg(feature = "tokio")]
+    pub fn set_capacity(mut self, max_read: usize) -> Parser {
+        self.bounded = Some(BoundedParser {
+            buffer: Vec::new(),
+            state: AnySendPartialState::default(),
+            window: vec![0u8; max_read],
+        });
+        self
+    }
+
     // public api
 
     /// Parses synchronously into a single value from the reader.
     pub fn parse_value<T: Read>(&mut self, mut reader: T) -> RedisResult<Value> {
+        let limits = self.limits;
         let mut decoder = &mut self.decoder;
-        let result = combine::decode!(decoder, reader, value(), |input, _| {
+        let result = combine::decode!(decoder, reader, value(limits, 0), |input, _| {
             combine::stream::easy::Stream::from(input)
         });
         match result {
@@ -267,6 +919,20 @@ impl Parser {
             Ok(result) => result,
         }
     }
+
+    /// Parses asynchronously into a single value from the reader, honoring
+    /// the bounded-read window configured via [`Parser::with_capacity`] if
+    /// any, and the limits configured via [`Parser::with_limits`] if any.
+    #[cfg(feature = "tokio")]
+    pub async fn parse_value_async<R>(&mut self, read: &mut R) -> RedisResult<Value>
+    where
+        R: AsyncRead + std::marker::Unpin,
+    {
+        match &mut self.bounded {
+            Some(bounded) => bounded.parse_value(read, self.limits).await,
+            None => parse_redis_value_async(&mut self.decoder, read, self.limits).await,
+        }
+    }
 }
 
 /// Parses bytes into a redis value.
@@ -277,3 +943,240 @@ pub fn parse_redis_value(bytes: &[u8]) -> RedisResult<Value> {
     let mut parser = Parser::new();
     parser.parse_value(bytes)
 }
+
+/// Like [`parse_redis_value`], but enforces `limits` while parsing.
+pub fn parse_redis_value_with_limits(bytes: &[u8], limits: Limits) -> RedisResult<Value> {
+    let mut parser = Parser::with_limits(limits);
+    parser.parse_value(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data(bytes: &[u8]) -> Value {
+        Value::Data(bytes.to_vec().into())
+    }
+
+    #[test]
+    fn parses_negative_length_bulk_string_as_nil() {
+        assert_eq!(parse_redis_value(b"$-1\r\n").unwrap(), Value::Nil);
+    }
+
+    #[test]
+    fn parses_streamed_bulk_string() {
+        let reply = b"$?\r\n;4\r\nHell\r\n;2\r\no!\r\n;0\r\n";
+        assert_eq!(parse_redis_value(reply).unwrap(), data(b"Hello!"));
+    }
+
+    #[test]
+    fn parses_streamed_array() {
+        let reply = b"*?\r\n:1\r\n:2\r\n.\r\n";
+        assert_eq!(
+            parse_redis_value(reply).unwrap(),
+            Value::Bulk(vec![Value::Int(1), Value::Int(2)])
+        );
+    }
+
+    #[test]
+    fn parses_double_infinities_and_nan() {
+        assert_eq!(parse_redis_value(b",inf\r\n").unwrap(), Value::Double(f64::INFINITY));
+        assert_eq!(parse_redis_value(b",-inf\r\n").unwrap(), Value::Double(f64::NEG_INFINITY));
+        match parse_redis_value(b",nan\r\n").unwrap() {
+            Value::Double(d) => assert!(d.is_nan()),
+            other => panic!("expected a double, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parses_attribute_wrapped_value() {
+        let reply = b"|1\r\n+key\r\n+value\r\n:42\r\n";
+        assert_eq!(parse_redis_value(reply).unwrap(), Value::Int(42));
+    }
+
+    #[test]
+    fn attribute_propagates_embedded_error() {
+        let reply = b"|1\r\n+key\r\n-ERR boom\r\n:42\r\n";
+        let err = parse_redis_value(reply).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ResponseError);
+    }
+
+    #[test]
+    fn parses_inline_command_by_splitting_whitespace() {
+        let reply = b"PING hello world\r\n";
+        assert_eq!(
+            parse_redis_value(reply).unwrap(),
+            Value::Bulk(vec![data(b"PING"), data(b"hello"), data(b"world")])
+        );
+    }
+
+    #[test]
+    fn parses_streamed_terminator_as_empty_aggregate() {
+        assert_eq!(parse_redis_value(b"*?\r\n.\r\n").unwrap(), Value::Bulk(vec![]));
+    }
+
+    fn limits(max_depth: usize, max_elements: usize, max_bulk_len: usize) -> Limits {
+        Limits { max_depth, max_elements, max_bulk_len }
+    }
+
+    #[test]
+    fn rejects_array_exceeding_max_depth() {
+        // A single array nested one level deeper than max_depth allows.
+        let reply = b"*1\r\n*1\r\n:1\r\n";
+        let err = parse_redis_value_with_limits(reply, limits(1, usize::MAX, usize::MAX))
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ResponseError);
+        // A single level of nesting is within the same limit.
+        assert!(parse_redis_value_with_limits(b"*1\r\n:1\r\n", limits(1, usize::MAX, usize::MAX)).is_ok());
+    }
+
+    #[test]
+    fn rejects_array_exceeding_max_elements() {
+        let err = parse_redis_value_with_limits(
+            b"*3\r\n:1\r\n:2\r\n:3\r\n",
+            limits(usize::MAX, 2, usize::MAX),
+        )
+        .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ResponseError);
+        assert!(parse_redis_value_with_limits(
+            b"*2\r\n:1\r\n:2\r\n",
+            limits(usize::MAX, 2, usize::MAX)
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn rejects_bulk_string_exceeding_max_bulk_len() {
+        let err =
+            parse_redis_value_with_limits(b"$5\r\nhello\r\n", limits(usize::MAX, usize::MAX, 4))
+                .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ResponseError);
+        assert!(
+            parse_redis_value_with_limits(b"$4\r\nhell\r\n", limits(usize::MAX, usize::MAX, 4))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn rejects_blob_error_and_verbatim_exceeding_max_bulk_len() {
+        let small = limits(usize::MAX, usize::MAX, 2);
+        assert!(parse_redis_value_with_limits(b"!5\r\nERR x\r\n", small).is_err());
+        assert!(parse_redis_value_with_limits(b"=5\r\ntxt:x\r\n", small).is_err());
+    }
+
+    #[test]
+    fn rejects_streamed_chunk_exceeding_max_bulk_len() {
+        let reply = b"$?\r\n;5\r\nhello\r\n;0\r\n";
+        assert!(
+            parse_redis_value_with_limits(reply, limits(usize::MAX, usize::MAX, 4)).is_err()
+        );
+    }
+
+    #[test]
+    fn rejects_attribute_map_exceeding_max_elements() {
+        let reply = b"|2\r\n+a\r\n+1\r\n+b\r\n+2\r\n:1\r\n";
+        let err = parse_redis_value_with_limits(reply, limits(usize::MAX, 1, usize::MAX))
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ResponseError);
+    }
+
+    #[cfg(feature = "tokio-util")]
+    #[test]
+    fn encoder_round_trips_every_value_variant_through_the_codec() {
+        use bytes::BytesMut;
+        use tokio_util::codec::{Decoder, Encoder};
+
+        let values = vec![
+            Value::Nil,
+            Value::Int(42),
+            Value::Okay,
+            Value::Status("hi".into()),
+            data(b"hello"),
+            Value::Bulk(vec![Value::Int(1), Value::Int(2)]),
+            Value::Boolean(true),
+            Value::Double(1.5),
+            Value::BigNumber("123456789012345".into()),
+            Value::VerbatimString { format: "txt".into(), text: "hi".into() },
+            Value::Map(vec![(Value::Int(1), Value::Int(2))]),
+            Value::Set(vec![Value::Int(1)]),
+            Value::Push(vec![Value::Int(1)]),
+        ];
+
+        let mut codec = ValueCodec::default();
+        let mut buf = BytesMut::new();
+        for value in &values {
+            codec.encode(value.clone(), &mut buf).unwrap();
+        }
+
+        let mut decoded = Vec::new();
+        while let Some(value) = codec.decode(&mut buf).unwrap() {
+            decoded.push(value);
+        }
+        assert_eq!(decoded, values);
+    }
+
+    #[cfg(all(feature = "tokio-util", feature = "bytes"))]
+    #[test]
+    fn zero_copy_decode_falls_back_to_the_slow_path_for_a_bulk_string_split_across_two_calls() {
+        use bytes::BytesMut;
+        use tokio_util::codec::Decoder;
+
+        let mut codec = ValueCodec::default();
+        let mut buf = BytesMut::from(&b"$6\r\nHel"[..]);
+        // Not enough bytes for either path to resolve the value yet.
+        assert_eq!(codec.decode(&mut buf).unwrap(), None);
+
+        // The rest of the reply arrives in a later call. `partial_in_progress`
+        // must steer this past the zero-copy fast path and into the slow path
+        // that's actually holding the continuation for it.
+        buf.extend_from_slice(b"lo!\r\n");
+        assert_eq!(codec.decode(&mut buf).unwrap(), Some(data(b"Hello!")));
+    }
+
+    #[cfg(all(feature = "tokio-util", feature = "bytes"))]
+    #[test]
+    fn zero_copy_decode_still_enforces_max_bulk_len() {
+        use bytes::BytesMut;
+        use tokio_util::codec::Decoder;
+
+        let mut codec = ValueCodec::default().set_limits(limits(usize::MAX, usize::MAX, 4));
+        let mut buf = BytesMut::from(&b"$5\r\nhello\r\n"[..]);
+        assert!(codec.decode(&mut buf).is_err());
+    }
+
+    /// An `AsyncRead` that hands back one queued chunk per `poll_read` call,
+    /// so a reply can be fed to [`Parser::parse_value_async`] in pieces
+    /// smaller than its declared length, the same way a slow socket would.
+    #[cfg(feature = "tokio")]
+    struct ChunkedReader {
+        chunks: std::collections::VecDeque<Vec<u8>>,
+    }
+
+    #[cfg(feature = "tokio")]
+    impl tokio::io::AsyncRead for ChunkedReader {
+        fn poll_read(
+            mut self: std::pin::Pin<&mut Self>,
+            _cx: &mut std::task::Context<'_>,
+            buf: &mut tokio::io::ReadBuf<'_>,
+        ) -> std::task::Poll<std::io::Result<()>> {
+            if let Some(chunk) = self.chunks.pop_front() {
+                buf.put_slice(&chunk);
+            }
+            std::task::Poll::Ready(Ok(()))
+        }
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn with_capacity_reassembles_a_reply_read_in_chunks_smaller_than_max_read() {
+        let mut reader = ChunkedReader {
+            chunks: vec![b"$6\r\n".to_vec(), b"Hel".to_vec(), b"lo!\r\n".to_vec()]
+                .into_iter()
+                .collect(),
+        };
+
+        let mut parser = Parser::with_capacity(8);
+        let value = parser.parse_value_async(&mut reader).await.unwrap();
+        assert_eq!(value, data(b"Hello!"));
+    }
+}
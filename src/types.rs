@@ -0,0 +1,143 @@
+use std::fmt;
+use std::io;
+
+/// A specialized result type for this crate's operations.
+pub type RedisResult<T> = Result<T, RedisError>;
+
+/// The payload of a [`Value::Data`] bulk string.
+///
+/// When the `bytes` feature is enabled this is a refcounted [`bytes::Bytes`],
+/// so the zero-copy codec fast path can hand back a view into the original
+/// read buffer instead of copying it into a fresh allocation; an owned
+/// `Vec<u8>` converts into it without an extra copy via `Bytes::from`.
+/// Without the feature it's a plain `Vec<u8>`.
+#[cfg(feature = "bytes")]
+pub type Payload = bytes::Bytes;
+#[cfg(not(feature = "bytes"))]
+pub type Payload = Vec<u8>;
+
+/// A single RESP2/RESP3 value, as produced by the parser.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    /// A nil response (`$-1\r\n`, `_\r\n`, ...).
+    Nil,
+    /// An integer response.
+    Int(i64),
+    /// A bulk string.
+    Data(Payload),
+    /// A nested array of values.
+    Bulk(Vec<Value>),
+    /// A status reply of `OK`.
+    Okay,
+    /// A status reply other than `OK`.
+    Status(String),
+    /// A RESP3 boolean.
+    Boolean(bool),
+    /// A RESP3 double.
+    Double(f64),
+    /// A RESP3 big number, kept as the decimal string the server sent.
+    BigNumber(String),
+    /// A RESP3 verbatim string, with its declared format (e.g. `txt`, `mkd`).
+    VerbatimString { format: String, text: String },
+    /// A RESP3 map.
+    Map(Vec<(Value, Value)>),
+    /// A RESP3 set.
+    Set(Vec<Value>),
+    /// A RESP3 out-of-band push message.
+    Push(Vec<Value>),
+}
+
+/// The kind of error a [`RedisError`] represents.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// A generic error reported by the server (`-ERR ...`).
+    ResponseError,
+    /// `EXECABORT`: a transaction was aborted.
+    ExecAbortError,
+    /// `LOADING`: the server is still loading its dataset.
+    BusyLoadingError,
+    /// `NOSCRIPT`: the referenced script isn't known to the server.
+    NoScriptError,
+    /// `MOVED`: the key lives on a different cluster node.
+    Moved,
+    /// `ASK`: the key is being migrated to a different cluster node.
+    Ask,
+    /// `TRYAGAIN`: the cluster is in the middle of a resharding operation.
+    TryAgain,
+    /// `CLUSTERDOWN`: the cluster is down.
+    ClusterDown,
+    /// `CROSSSLOT`: a command's keys don't all hash to the same slot.
+    CrossSlot,
+    /// `MASTERDOWN`: the server has no reachable master.
+    MasterDown,
+    /// Any other server-reported error code this crate doesn't special-case.
+    ExtensionError,
+    /// An I/O error occurred while reading or writing.
+    IoError,
+}
+
+/// An error raised while talking to redis or parsing one of its replies.
+#[derive(Debug)]
+pub struct RedisError {
+    kind: ErrorKind,
+    description: &'static str,
+    detail: Option<String>,
+}
+
+impl RedisError {
+    /// The kind of error this is.
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// Extra detail about the error, if any was captured.
+    pub fn detail(&self) -> Option<&str> {
+        self.detail.as_deref()
+    }
+}
+
+impl fmt::Display for RedisError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.detail {
+            Some(detail) => write!(f, "{}: {}", self.description, detail),
+            None => write!(f, "{}", self.description),
+        }
+    }
+}
+
+impl std::error::Error for RedisError {}
+
+impl From<(ErrorKind, &'static str)> for RedisError {
+    fn from((kind, description): (ErrorKind, &'static str)) -> Self {
+        RedisError { kind, description, detail: None }
+    }
+}
+
+impl From<(ErrorKind, &'static str, String)> for RedisError {
+    fn from((kind, description, detail): (ErrorKind, &'static str, String)) -> Self {
+        RedisError { kind, description, detail: Some(detail) }
+    }
+}
+
+impl From<io::Error> for RedisError {
+    fn from(err: io::Error) -> Self {
+        RedisError {
+            kind: ErrorKind::IoError,
+            description: "I/O error",
+            detail: Some(err.to_string()),
+        }
+    }
+}
+
+/// Builds the error for a server error code this crate doesn't special-case,
+/// keeping the offending code and any detail the server sent along with it.
+pub fn make_extension_error(code: &str, detail: Option<&str>) -> RedisError {
+    RedisError {
+        kind: ErrorKind::ExtensionError,
+        description: "An error was signalled by the server",
+        detail: Some(match detail {
+            Some(detail) => format!("{}: {}", code, detail),
+            None => code.to_string(),
+        }),
+    }
+}